@@ -0,0 +1,115 @@
+// MIT License
+
+// Copyright (c) 2017 Jerome Froelich
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! `BuildHasher` implementations for the hashers in this crate, plus
+//! `HashMap`/`HashSet` type aliases built on top of them, so the faster
+//! hashes here can be wired directly into `std`'s collections the way
+//! the `fnv` crate's `FnvHashMap` is.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
+
+use fnv::Fnv;
+use murmur::Murmur3;
+
+/// A `BuildHasher` that constructs `Fnv` hashers.
+#[derive(Clone, Copy, Default)]
+pub struct FnvBuildHasher;
+
+impl BuildHasher for FnvBuildHasher {
+    type Hasher = Fnv;
+
+    fn build_hasher(&self) -> Fnv {
+        Fnv::new()
+    }
+}
+
+/// A `HashMap` using a default `FnvBuildHasher`.
+pub type FnvHashMap<K, V> = HashMap<K, V, FnvBuildHasher>;
+
+/// A `HashSet` using a default `FnvBuildHasher`.
+pub type FnvHashSet<T> = HashSet<T, FnvBuildHasher>;
+
+/// A `BuildHasher` that constructs `Murmur3` hashers seeded with
+/// `seed`. Since Murmur3 is a seeded hash, giving each process (or each
+/// map) its own `Murmur3BuildHasher::new(seed)` lets callers randomize
+/// the seed to mitigate hash-flooding the way `std`'s `RandomState`
+/// does for `SipHash`.
+#[derive(Clone, Copy)]
+pub struct Murmur3BuildHasher {
+    seed: u32,
+}
+
+impl Murmur3BuildHasher {
+    /// Create a `Murmur3BuildHasher` whose hashers are seeded with
+    /// `seed`.
+    pub fn new(seed: u32) -> Self {
+        Murmur3BuildHasher { seed }
+    }
+}
+
+impl Default for Murmur3BuildHasher {
+    /// Create a `Murmur3BuildHasher` with the default seed of `0`.
+    fn default() -> Self {
+        Murmur3BuildHasher::new(0)
+    }
+}
+
+impl BuildHasher for Murmur3BuildHasher {
+    type Hasher = Murmur3;
+
+    fn build_hasher(&self) -> Murmur3 {
+        Murmur3::new_with_key(self.seed)
+    }
+}
+
+/// A `HashMap` using a default `Murmur3BuildHasher`.
+pub type Murmur3HashMap<K, V> = HashMap<K, V, Murmur3BuildHasher>;
+
+/// A `HashSet` using a default `Murmur3BuildHasher`.
+pub type Murmur3HashSet<T> = HashSet<T, Murmur3BuildHasher>;
+
+#[cfg(test)]
+mod tests {
+    use super::{FnvHashMap, Murmur3BuildHasher, Murmur3HashMap};
+
+    #[test]
+    fn fnv_hash_map() {
+        let mut map: FnvHashMap<&str, i32> = FnvHashMap::default();
+        map.insert("foo", 1);
+        map.insert("bar", 2);
+
+        assert_eq!(map.get("foo"), Some(&1));
+        assert_eq!(map.get("bar"), Some(&2));
+    }
+
+    #[test]
+    fn murmur3_hash_map() {
+        let mut map: Murmur3HashMap<&str, i32> =
+            Murmur3HashMap::with_hasher(Murmur3BuildHasher::new(42));
+        map.insert("foo", 1);
+        map.insert("bar", 2);
+
+        assert_eq!(map.get("foo"), Some(&1));
+        assert_eq!(map.get("bar"), Some(&2));
+    }
+}
@@ -20,10 +20,15 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-//! FNV is a 64 bit implementation of the
-//! [Fowler–Noll–Vo hash algorthim](http://isthe.com/chongo/tech/comp/fnv/),
-//! specifically the
-//! [FNV-1a alternate algorithm](http://isthe.com/chongo/tech/comp/fnv/#FNV-1a).
+//! FNV is an implementation of the
+//! [Fowler–Noll–Vo hash algorthim](http://isthe.com/chongo/tech/comp/fnv/).
+//! `Fnv` is the 64 bit
+//! [FNV-1a alternate algorithm](http://isthe.com/chongo/tech/comp/fnv/#FNV-1a),
+//! while `Fnv32` and `Fnv64` expose the full FNV matrix documented in the
+//! reference implementations: FNV-0, the original algorithm; FNV-1, which
+//! fixes FNV-0's weakness on all-zero input by seeding with a non-zero
+//! offset basis; and FNV-1a, which swaps the order of the XOR and
+//! multiply steps for slightly better dispersion.
 //!
 //! FNV is a non-cryptographic hash function that is designed to be fast
 //! while maintaining a low collision rate. FNV is best for applications
@@ -55,6 +60,9 @@ use std::hash::Hasher;
 const PRIME: u64 = 1099511628211;
 const OFFSET_BASIS: u64 = 14695981039346656037;
 
+const PRIME_32: u32 = 16777619;
+const OFFSET_BASIS_32: u32 = 2166136261;
+
 /// An implementation of the Fowler–Noll–Vo hash function, specifically
 /// the FNV-1a alternative algorithim.
 #[allow(missing_copy_implementations)]
@@ -92,10 +100,130 @@ impl Hasher for Fnv {
     }
 }
 
+/// The historical FNV algorithm variants.
+///
+/// `Fnv0` is the original algorithm, seeded with an offset basis of
+/// `0`. `Fnv1` fixes `Fnv0`'s weakness on all-zero input by seeding
+/// with a non-zero offset basis, multiplying by the prime before
+/// XORing in each byte. `Fnv1a` swaps that order, XORing in each byte
+/// before multiplying, for slightly better dispersion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FnvVariant {
+    Fnv0,
+    Fnv1,
+    Fnv1a,
+}
+
+/// A 32 bit FNV hasher supporting the FNV-0, FNV-1, and FNV-1a variants.
+#[allow(missing_copy_implementations)]
+pub struct Fnv32 {
+    state: u32,
+    variant: FnvVariant,
+}
+
+impl Fnv32 {
+    /// Create a new 32 bit FNV hasher of the given `variant` with its
+    /// default initial state.
+    pub fn new(variant: FnvVariant) -> Self {
+        let state = match variant {
+            FnvVariant::Fnv0 => 0,
+            FnvVariant::Fnv1 | FnvVariant::Fnv1a => OFFSET_BASIS_32,
+        };
+        Fnv32 { state, variant }
+    }
+
+    /// Create a new 32 bit FNV hasher of the given `variant` whose
+    /// initial state is `key`.
+    pub fn new_with_key(variant: FnvVariant, key: u32) -> Self {
+        Fnv32 { state: key, variant }
+    }
+}
+
+impl Hasher for Fnv32 {
+    fn finish(&self) -> u64 {
+        self.state as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            match self.variant {
+                FnvVariant::Fnv0 | FnvVariant::Fnv1 => {
+                    self.state = self.state.wrapping_mul(PRIME_32);
+                    self.state ^= *byte as u32;
+                }
+                FnvVariant::Fnv1a => {
+                    self.state ^= *byte as u32;
+                    self.state = self.state.wrapping_mul(PRIME_32);
+                }
+            }
+        }
+    }
+}
+
+/// A 64 bit FNV hasher supporting the FNV-0, FNV-1, and FNV-1a variants.
+#[allow(missing_copy_implementations)]
+pub struct Fnv64 {
+    state: u64,
+    variant: FnvVariant,
+}
+
+impl Fnv64 {
+    /// Create a new 64 bit FNV hasher of the given `variant` with its
+    /// default initial state.
+    pub fn new(variant: FnvVariant) -> Self {
+        let state = match variant {
+            FnvVariant::Fnv0 => 0,
+            FnvVariant::Fnv1 | FnvVariant::Fnv1a => OFFSET_BASIS,
+        };
+        Fnv64 { state, variant }
+    }
+
+    /// Create a new 64 bit FNV hasher of the given `variant` whose
+    /// initial state is `key`.
+    pub fn new_with_key(variant: FnvVariant, key: u64) -> Self {
+        Fnv64 { state: key, variant }
+    }
+}
+
+impl Hasher for Fnv64 {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            match self.variant {
+                FnvVariant::Fnv0 | FnvVariant::Fnv1 => {
+                    self.state = self.state.wrapping_mul(PRIME);
+                    self.state ^= *byte as u64;
+                }
+                FnvVariant::Fnv1a => {
+                    self.state ^= *byte as u64;
+                    self.state = self.state.wrapping_mul(PRIME);
+                }
+            }
+        }
+    }
+}
+
+/// Compute the 32 bit FNV hash of `bytes` using the given `variant`.
+pub fn fnv32(variant: FnvVariant, bytes: &[u8]) -> u32 {
+    let mut hasher = Fnv32::new(variant);
+    hasher.write(bytes);
+    hasher.finish() as u32
+}
+
+/// Compute the 64 bit FNV hash of `bytes` using the given `variant`.
+pub fn fnv64(variant: FnvVariant, bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv64::new(variant);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use std::hash::Hasher;
-    use super::Fnv;
+    use super::{fnv32, fnv64, Fnv, FnvVariant};
 
     fn fnv1a(bytes: &[u8]) -> u64 {
         let mut hasher = Fnv::new();
@@ -109,4 +237,34 @@ mod tests {
         assert_eq!(fnv1a(b"bar"), 16101355973854746);
         assert_eq!(fnv1a(b"baz"), 16092559880829058);
     }
+
+    #[test]
+    fn fnv32_tests() {
+        assert_eq!(fnv32(FnvVariant::Fnv0, b"foo"), 2415750696);
+        assert_eq!(fnv32(FnvVariant::Fnv1, b"foo"), 1083137555);
+        assert_eq!(fnv32(FnvVariant::Fnv1a, b"foo"), 2851307223);
+
+        assert_eq!(fnv32(FnvVariant::Fnv0, b"bar"), 1844620055);
+        assert_eq!(fnv32(FnvVariant::Fnv1, b"bar"), 513390112);
+        assert_eq!(fnv32(FnvVariant::Fnv1a, b"bar"), 1991736602);
+
+        assert_eq!(fnv32(FnvVariant::Fnv0, b"baz"), 1844620063);
+        assert_eq!(fnv32(FnvVariant::Fnv1, b"baz"), 513390120);
+        assert_eq!(fnv32(FnvVariant::Fnv1a, b"baz"), 1857515650);
+    }
+
+    #[test]
+    fn fnv64_tests() {
+        assert_eq!(fnv64(FnvVariant::Fnv0, b"foo"), 97547572123950792);
+        assert_eq!(fnv64(FnvVariant::Fnv1, b"foo"), 15621798640163566899);
+        assert_eq!(fnv64(FnvVariant::Fnv1a, b"foo"), 15902901984413996407);
+
+        assert_eq!(fnv64(FnvVariant::Fnv0, b"bar"), 93851014030662391);
+        assert_eq!(fnv64(FnvVariant::Fnv1, b"bar"), 15625701906442958976);
+        assert_eq!(fnv64(FnvVariant::Fnv1a, b"bar"), 16101355973854746);
+
+        assert_eq!(fnv64(FnvVariant::Fnv0, b"baz"), 93851014030662399);
+        assert_eq!(fnv64(FnvVariant::Fnv1, b"baz"), 15625701906442958984);
+        assert_eq!(fnv64(FnvVariant::Fnv1a, b"baz"), 16092559880829058);
+    }
 }
\ No newline at end of file
@@ -25,10 +25,39 @@ extern crate byteorder;
 /// [Fowler–Noll–Vo hash algorthim](http://isthe.com/chongo/tech/comp/fnv/).
 pub use self::fnv::Fnv;
 
+/// `Fnv32` and `Fnv64` provide 32 and 64 bit implementations of the
+/// [Fowler–Noll–Vo hash algorthim](http://isthe.com/chongo/tech/comp/fnv/)
+/// supporting the FNV-0, FNV-1, and FNV-1a variants selected by
+/// `FnvVariant`. `fnv32` and `fnv64` are convenience functions for
+/// one-shot hashing.
+pub use self::fnv::{fnv32, fnv64, Fnv32, Fnv64, FnvVariant};
+
 /// `Murmur_hash3_x86_32` provides an implementation of the 32 bit
 /// version of the
 /// [Murmur3 hash function](https://github.com/aappleby/smhasher).
 pub use self::murmur::murmurhash3_x86_32;
 
+/// `murmurhash3_x64_128` and `murmurhash3_x86_128` provide 128 bit
+/// versions of the
+/// [Murmur3 hash function](https://github.com/aappleby/smhasher),
+/// optimized for x64 and x86 architectures respectively.
+pub use self::murmur::{murmurhash3_x64_128, murmurhash3_x86_128};
+
+/// `Murmur3` provides an incremental, `std::hash::Hasher`-compatible
+/// implementation of the 32 bit version of the
+/// [Murmur3 hash function](https://github.com/aappleby/smhasher).
+pub use self::murmur::Murmur3;
+
+/// `FnvBuildHasher` and `Murmur3BuildHasher` are `std::hash::BuildHasher`
+/// implementations for `Fnv` and `Murmur3`, with `FnvHashMap` /
+/// `FnvHashSet` and `Murmur3HashMap` / `Murmur3HashSet` type aliases
+/// for wiring them directly into `std`'s collections.
+pub use self::build_hasher::{
+    FnvBuildHasher, FnvHashMap, FnvHashSet, Murmur3BuildHasher, Murmur3HashMap, Murmur3HashSet,
+};
+
+mod build_hasher;
 mod fnv;
 mod murmur;
+#[cfg(test)]
+mod verify;
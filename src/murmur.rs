@@ -49,6 +49,7 @@
 //! }
 //! ```
 
+use std::hash::Hasher;
 use std::io::Cursor;
 use byteorder::{LittleEndian, ReadBytesExt};
 
@@ -68,27 +69,26 @@ pub fn murmurhash3_x86_32(seed: u32, bytes: &[u8]) -> u32 {
         k1 = k1.wrapping_mul(c1).rotate_left(15).wrapping_mul(c2);
 
         h1 ^= k1;
-        h1.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
+        h1 = h1.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64);
     }
 
     let bytes_left = bytes.len() - (nblocks * 4);
 
-    let mut k1 = match bytes_left {
+    let k1 = match bytes_left {
         3 => {
-            let mut k1 = reader.read_u16::<LittleEndian>().unwrap() as u32;
-            k1 <<= 8;
-            k1 += reader.read_u8().unwrap() as u32;
-            k1
-        }
-        2 => reader.read_u16::<LittleEndian>().unwrap() as u32,
-        1 => reader.read_u8().unwrap() as u32,
-        _ => {
-            panic!("Invalid number of bytes left");
+            let k1 = reader.read_u16::<LittleEndian>().unwrap() as u32;
+            let k1 = k1 | (reader.read_u8().unwrap() as u32) << 16;
+            Some(k1)
         }
+        2 => Some(reader.read_u16::<LittleEndian>().unwrap() as u32),
+        1 => Some(reader.read_u8().unwrap() as u32),
+        _ => None,
     };
 
-    k1 = k1.wrapping_mul(c1).rotate_left(15).wrapping_mul(c2);
-    h1 ^= k1;
+    if let Some(mut k1) = k1 {
+        k1 = k1.wrapping_mul(c1).rotate_left(15).wrapping_mul(c2);
+        h1 ^= k1;
+    }
 
     h1 ^= bytes.len() as u32;
     h1 ^= h1.wrapping_shr(16);
@@ -100,14 +100,354 @@ pub fn murmurhash3_x86_32(seed: u32, bytes: &[u8]) -> u32 {
     h1
 }
 
+const MURMUR3_C1: u32 = 0xcc9e2d51;
+const MURMUR3_C2: u32 = 0x1b873593;
+
+fn murmur3_mix_block(h1: u32, k1: u32) -> u32 {
+    let k1 = k1.wrapping_mul(MURMUR3_C1).rotate_left(15).wrapping_mul(MURMUR3_C2);
+    let h1 = h1 ^ k1;
+    h1.rotate_left(13).wrapping_mul(5).wrapping_add(0xe6546b64)
+}
+
+/// An incremental implementation of the 32 bit version of the Murmur3
+/// hash function which implements `std::hash::Hasher`.
+///
+/// `murmurhash3_x86_32` requires the entire input to be available up
+/// front, so it cannot be used with `std`'s `Hash`/`Hasher` machinery.
+/// `Murmur3` buffers data across calls to `write` instead, mixing each
+/// completed 4 byte block as it arrives and holding any leftover bytes
+/// in a tail buffer until `finish` is called.
+pub struct Murmur3 {
+    h1: u32,
+    tail: [u8; 4],
+    tail_len: usize,
+    total_len: usize,
+}
+
+impl Murmur3 {
+    /// Create a new Murmur3 Hasher with the default seed of `0`.
+    pub fn new() -> Self {
+        Murmur3::new_with_key(0)
+    }
+
+    /// Create a new Murmur3 Hasher whose initial seed is `key`.
+    pub fn new_with_key(key: u32) -> Murmur3 {
+        Murmur3 {
+            h1: key,
+            tail: [0; 4],
+            tail_len: 0,
+            total_len: 0,
+        }
+    }
+}
+
+impl Default for Murmur3 {
+    /// Create a default Murmur3 Hasher.
+    fn default() -> Murmur3 {
+        Murmur3::new()
+    }
+}
+
+impl Hasher for Murmur3 {
+    fn finish(&self) -> u64 {
+        let mut h1 = self.h1;
+
+        if self.tail_len > 0 {
+            let mut k1: u32 = 0;
+            for i in 0..self.tail_len {
+                k1 |= (self.tail[i] as u32) << (8 * i);
+            }
+            k1 = k1.wrapping_mul(MURMUR3_C1).rotate_left(15).wrapping_mul(MURMUR3_C2);
+            h1 ^= k1;
+        }
+
+        h1 ^= self.total_len as u32;
+        h1 ^= h1.wrapping_shr(16);
+        h1 = h1.wrapping_mul(0x85ebca6b);
+        h1 ^= h1.wrapping_shr(13);
+        h1 = h1.wrapping_mul(0xc2b2ae35);
+        h1 ^= h1.wrapping_shr(16);
+
+        h1 as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.total_len += bytes.len();
+        let mut bytes = bytes;
+
+        if self.tail_len > 0 {
+            let needed = (4 - self.tail_len).min(bytes.len());
+            self.tail[self.tail_len..self.tail_len + needed].copy_from_slice(&bytes[..needed]);
+            self.tail_len += needed;
+            bytes = &bytes[needed..];
+
+            if self.tail_len < 4 {
+                return;
+            }
+
+            let mut k1: u32 = 0;
+            for (i, byte) in self.tail.iter().enumerate() {
+                k1 |= (*byte as u32) << (8 * i);
+            }
+            self.h1 = murmur3_mix_block(self.h1, k1);
+            self.tail_len = 0;
+        }
+
+        let mut chunks = bytes.chunks_exact(4);
+        for chunk in &mut chunks {
+            let k1 = (chunk[0] as u32)
+                | (chunk[1] as u32) << 8
+                | (chunk[2] as u32) << 16
+                | (chunk[3] as u32) << 24;
+            self.h1 = murmur3_mix_block(self.h1, k1);
+        }
+
+        let remainder = chunks.remainder();
+        self.tail[..remainder.len()].copy_from_slice(remainder);
+        self.tail_len = remainder.len();
+    }
+}
+
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^= h >> 16;
+    h
+}
+
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51afd7ed558ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ceb9fe1a85ec53);
+    k ^= k >> 33;
+    k
+}
+
+/// An implementation of the 128 bit version of the Murmur3 hash
+/// function, optimized for x64 architectures.
+///
+/// The two 64 bit halves of the digest, `h1` and `h2`, are packed into
+/// the returned `u128` as `h1 << 64 | h2`.
+pub fn murmurhash3_x64_128(seed: u64, bytes: &[u8]) -> u128 {
+    let nblocks = bytes.len() / 16;
+    let mut reader = Cursor::new(bytes);
+
+    let mut h1 = seed;
+    let mut h2 = seed;
+
+    let c1: u64 = 0x87c37b91114253d5;
+    let c2: u64 = 0x4cf5ad432745937f;
+
+    for _ in 0..nblocks {
+        let mut k1 = reader.read_u64::<LittleEndian>().unwrap();
+        let mut k2 = reader.read_u64::<LittleEndian>().unwrap();
+
+        k1 = k1.wrapping_mul(c1).rotate_left(31).wrapping_mul(c2);
+        h1 ^= k1;
+        h1 = h1.rotate_left(27).wrapping_add(h2);
+        h1 = h1.wrapping_mul(5).wrapping_add(0x52dce729);
+
+        k2 = k2.wrapping_mul(c2).rotate_left(33).wrapping_mul(c1);
+        h2 ^= k2;
+        h2 = h2.rotate_left(31).wrapping_add(h1);
+        h2 = h2.wrapping_mul(5).wrapping_add(0x38495ab5);
+    }
+
+    let bytes_left = bytes.len() - (nblocks * 16);
+    let mut k1: u64 = 0;
+    let mut k2: u64 = 0;
+
+    for i in 0..bytes_left.min(8) {
+        k1 |= (reader.read_u8().unwrap() as u64) << (8 * i);
+    }
+    for i in 0..bytes_left.saturating_sub(8) {
+        k2 |= (reader.read_u8().unwrap() as u64) << (8 * i);
+    }
+
+    if bytes_left > 8 {
+        k2 = k2.wrapping_mul(c2).rotate_left(33).wrapping_mul(c1);
+        h2 ^= k2;
+    }
+    if bytes_left >= 1 {
+        k1 = k1.wrapping_mul(c1).rotate_left(31).wrapping_mul(c2);
+        h1 ^= k1;
+    }
+
+    h1 ^= bytes.len() as u64;
+    h2 ^= bytes.len() as u64;
+
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    h1 = fmix64(h1);
+    h2 = fmix64(h2);
+
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    ((h1 as u128) << 64) | h2 as u128
+}
+
+/// An implementation of the 128 bit version of the Murmur3 hash
+/// function, optimized for x86 architectures.
+///
+/// The four 32 bit words of the digest, `h1` through `h4`, are packed
+/// into the returned `u128` as `h1 << 96 | h2 << 64 | h3 << 32 | h4`.
+pub fn murmurhash3_x86_128(seed: u32, bytes: &[u8]) -> u128 {
+    let nblocks = bytes.len() / 16;
+    let mut reader = Cursor::new(bytes);
+
+    let mut h1 = seed;
+    let mut h2 = seed;
+    let mut h3 = seed;
+    let mut h4 = seed;
+
+    let c1: u32 = 0x239b961b;
+    let c2: u32 = 0xab0e9789;
+    let c3: u32 = 0x38b34ae5;
+    let c4: u32 = 0xa1e38b93;
+
+    for _ in 0..nblocks {
+        let mut k1 = reader.read_u32::<LittleEndian>().unwrap();
+        let mut k2 = reader.read_u32::<LittleEndian>().unwrap();
+        let mut k3 = reader.read_u32::<LittleEndian>().unwrap();
+        let mut k4 = reader.read_u32::<LittleEndian>().unwrap();
+
+        k1 = k1.wrapping_mul(c1).rotate_left(15).wrapping_mul(c2);
+        h1 ^= k1;
+        h1 = h1.rotate_left(19).wrapping_add(h2);
+        h1 = h1.wrapping_mul(5).wrapping_add(0x561ccd1b);
+
+        k2 = k2.wrapping_mul(c2).rotate_left(16).wrapping_mul(c3);
+        h2 ^= k2;
+        h2 = h2.rotate_left(17).wrapping_add(h3);
+        h2 = h2.wrapping_mul(5).wrapping_add(0x0bcaa747);
+
+        k3 = k3.wrapping_mul(c3).rotate_left(17).wrapping_mul(c4);
+        h3 ^= k3;
+        h3 = h3.rotate_left(15).wrapping_add(h4);
+        h3 = h3.wrapping_mul(5).wrapping_add(0x96cd1c35);
+
+        k4 = k4.wrapping_mul(c4).rotate_left(18).wrapping_mul(c1);
+        h4 ^= k4;
+        h4 = h4.rotate_left(13).wrapping_add(h1);
+        h4 = h4.wrapping_mul(5).wrapping_add(0x32ac3b17);
+    }
+
+    let bytes_left = bytes.len() - (nblocks * 16);
+    let mut k1: u32 = 0;
+    let mut k2: u32 = 0;
+    let mut k3: u32 = 0;
+    let mut k4: u32 = 0;
+
+    for i in 0..bytes_left.min(4) {
+        k1 |= (reader.read_u8().unwrap() as u32) << (8 * i);
+    }
+    for i in 0..bytes_left.saturating_sub(4).min(4) {
+        k2 |= (reader.read_u8().unwrap() as u32) << (8 * i);
+    }
+    for i in 0..bytes_left.saturating_sub(8).min(4) {
+        k3 |= (reader.read_u8().unwrap() as u32) << (8 * i);
+    }
+    for i in 0..bytes_left.saturating_sub(12).min(4) {
+        k4 |= (reader.read_u8().unwrap() as u32) << (8 * i);
+    }
+
+    if bytes_left > 12 {
+        k4 = k4.wrapping_mul(c4).rotate_left(18).wrapping_mul(c1);
+        h4 ^= k4;
+    }
+    if bytes_left > 8 {
+        k3 = k3.wrapping_mul(c3).rotate_left(17).wrapping_mul(c4);
+        h3 ^= k3;
+    }
+    if bytes_left > 4 {
+        k2 = k2.wrapping_mul(c2).rotate_left(16).wrapping_mul(c3);
+        h2 ^= k2;
+    }
+    if bytes_left >= 1 {
+        k1 = k1.wrapping_mul(c1).rotate_left(15).wrapping_mul(c2);
+        h1 ^= k1;
+    }
+
+    let len = bytes.len() as u32;
+    h1 ^= len;
+    h2 ^= len;
+    h3 ^= len;
+    h4 ^= len;
+
+    h1 = h1.wrapping_add(h2).wrapping_add(h3).wrapping_add(h4);
+    h2 = h2.wrapping_add(h1);
+    h3 = h3.wrapping_add(h1);
+    h4 = h4.wrapping_add(h1);
+
+    h1 = fmix32(h1);
+    h2 = fmix32(h2);
+    h3 = fmix32(h3);
+    h4 = fmix32(h4);
+
+    h1 = h1.wrapping_add(h2).wrapping_add(h3).wrapping_add(h4);
+    h2 = h2.wrapping_add(h1);
+    h3 = h3.wrapping_add(h1);
+    h4 = h4.wrapping_add(h1);
+
+    ((h1 as u128) << 96) | ((h2 as u128) << 64) | ((h3 as u128) << 32) | h4 as u128
+}
+
 #[cfg(test)]
 mod tests {
-    use super::murmurhash3_x86_32;
+    use std::hash::Hasher;
+    use super::{murmurhash3_x64_128, murmurhash3_x86_128, murmurhash3_x86_32, Murmur3};
 
     #[test]
     fn basic_tests() {
-        assert_eq!(murmurhash3_x86_32(42, b"foo"), 1490047128);
-        assert_eq!(murmurhash3_x86_32(123456789, b"bar"), 2996396419);
-        assert_eq!(murmurhash3_x86_32(864217, b"baz"), 174231400);
+        assert_eq!(murmurhash3_x86_32(42, b"foo"), 2972666014);
+        assert_eq!(murmurhash3_x86_32(123456789, b"bar"), 2091367354);
+        assert_eq!(murmurhash3_x86_32(864217, b"baz"), 1640568805);
+    }
+
+    #[test]
+    fn murmur3_hasher_matches_reference() {
+        let mut hasher = Murmur3::new_with_key(42);
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish(), 1018276128);
+
+        let mut hasher = Murmur3::new_with_key(123456789);
+        hasher.write(b"hello world");
+        assert_eq!(hasher.finish(), 4028245297);
+
+        let mut hasher = Murmur3::new_with_key(1);
+        hasher.write(b"");
+        assert_eq!(hasher.finish(), 1364076727);
+    }
+
+    #[test]
+    fn murmur3_hasher_is_incremental() {
+        let mut one_shot = Murmur3::new_with_key(42);
+        one_shot.write(b"foobar");
+
+        let mut byte_by_byte = Murmur3::new_with_key(42);
+        for byte in b"foobar" {
+            byte_by_byte.write(&[*byte]);
+        }
+
+        assert_eq!(one_shot.finish(), byte_by_byte.finish());
+    }
+
+    #[test]
+    fn x64_128_tests() {
+        assert_eq!(murmurhash3_x64_128(42, b"foo"), 324781359287917217228598183127461960361);
+        assert_eq!(murmurhash3_x64_128(123456789, b"bar"), 237885781236886471513740035726947065341);
+        assert_eq!(murmurhash3_x64_128(864217, b"baz"), 332879001032763070445101024427939538481);
+    }
+
+    #[test]
+    fn x86_128_tests() {
+        assert_eq!(murmurhash3_x86_128(42, b"foo"), 248556056066446689118136574237193277455);
+        assert_eq!(murmurhash3_x86_128(123456789, b"bar"), 186761207302306246930298969524973120164);
+        assert_eq!(murmurhash3_x86_128(864217, b"baz"), 223199260642733055108895111649418989405);
     }
 }
\ No newline at end of file
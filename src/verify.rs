@@ -0,0 +1,223 @@
+// MIT License
+
+// Copyright (c) 2017 Jerome Froelich
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! This module is test-only: it implements the
+//! [SMHasher](https://github.com/aappleby/smhasher) "verification" self
+//! test plus a couple of the simpler SMHasher quality checks, so
+//! contributors have a single reproducible number to confirm a hasher in
+//! this crate still matches its reference implementation, and basic
+//! sanity checks that it still avalanches and distributes well. None of
+//! this is exposed outside the crate; it only runs under `cargo test`.
+
+use fnv::{fnv32, fnv64, FnvVariant};
+use murmur::{murmurhash3_x64_128, murmurhash3_x86_128, murmurhash3_x86_32};
+
+/// Computes the canonical SMHasher verification value for a 32 bit
+/// hash: hash keys of length `0..256`, where key `i` is `i` bytes long
+/// and filled with `key[j] = j`, with seed `256 - i`; write each result
+/// little-endian into an output buffer; hash that buffer with seed `0`
+/// and return the low 32 bits.
+fn verification_32<F: Fn(u32, &[u8]) -> u32>(hash: F) -> u32 {
+    let mut key = [0u8; 256];
+    let mut hashes = [0u8; 256 * 4];
+
+    for i in 0..256 {
+        key[i] = i as u8;
+        let h = hash((256 - i) as u32, &key[..i]);
+        hashes[i * 4..i * 4 + 4].copy_from_slice(&h.to_le_bytes());
+    }
+
+    hash(0, &hashes)
+}
+
+/// As `verification_32`, but for a 64 bit hash: each per-key digest is
+/// written as 8 little-endian bytes, and the low 32 bits of the final
+/// 64 bit digest are returned.
+fn verification_64<F: Fn(u32, &[u8]) -> u64>(hash: F) -> u32 {
+    let mut key = [0u8; 256];
+    let mut hashes = [0u8; 256 * 8];
+
+    for i in 0..256 {
+        key[i] = i as u8;
+        let h = hash((256 - i) as u32, &key[..i]);
+        hashes[i * 8..i * 8 + 8].copy_from_slice(&h.to_le_bytes());
+    }
+
+    hash(0, &hashes) as u32
+}
+
+/// As `verification_32`, but for the 128 bit x64 Murmur3 variant, whose
+/// `h1`/`h2` halves are written out in the same `h1`-then-`h2` byte
+/// order as the reference SMHasher implementation, regardless of how
+/// `murmurhash3_x64_128` packs them into a `u128`.
+fn verification_x64_128() -> u32 {
+    let mut key = [0u8; 256];
+    let mut hashes = [0u8; 256 * 16];
+
+    for i in 0..256 {
+        key[i] = i as u8;
+        let packed = murmurhash3_x64_128((256 - i) as u64, &key[..i]);
+        let h1 = (packed >> 64) as u64;
+        let h2 = packed as u64;
+        hashes[i * 16..i * 16 + 8].copy_from_slice(&h1.to_le_bytes());
+        hashes[i * 16 + 8..i * 16 + 16].copy_from_slice(&h2.to_le_bytes());
+    }
+
+    let final_packed = murmurhash3_x64_128(0, &hashes);
+    (final_packed >> 64) as u32
+}
+
+/// As `verification_x64_128`, but for the 128 bit x86 Murmur3 variant
+/// and its four 32 bit `h1..h4` words.
+fn verification_x86_128() -> u32 {
+    let mut key = [0u8; 256];
+    let mut hashes = [0u8; 256 * 16];
+
+    for i in 0..256 {
+        key[i] = i as u8;
+        let packed = murmurhash3_x86_128((256 - i) as u32, &key[..i]);
+        let h1 = (packed >> 96) as u32;
+        let h2 = (packed >> 64) as u32;
+        let h3 = (packed >> 32) as u32;
+        let h4 = packed as u32;
+        hashes[i * 16..i * 16 + 4].copy_from_slice(&h1.to_le_bytes());
+        hashes[i * 16 + 4..i * 16 + 8].copy_from_slice(&h2.to_le_bytes());
+        hashes[i * 16 + 8..i * 16 + 12].copy_from_slice(&h3.to_le_bytes());
+        hashes[i * 16 + 12..i * 16 + 16].copy_from_slice(&h4.to_le_bytes());
+    }
+
+    let final_packed = murmurhash3_x86_128(0, &hashes);
+    (final_packed >> 96) as u32
+}
+
+/// Returns the fraction of output bits that flip, averaged over flipping
+/// each input bit of `input` one at a time. A well avalanching hash
+/// keeps this close to `0.5`.
+fn avalanche_bias<F: Fn(&[u8]) -> u32>(hash: F, input: &[u8]) -> f64 {
+    let base = hash(input);
+    let total_bits = input.len() * 8;
+    let mut flipped_bits = 0u32;
+
+    for bit in 0..total_bits {
+        let mut flipped = input.to_vec();
+        flipped[bit / 8] ^= 1 << (bit % 8);
+        flipped_bits += (base ^ hash(&flipped)).count_ones();
+    }
+
+    f64::from(flipped_bits) / (total_bits as f64 * 32.0)
+}
+
+/// A basic chi-squared statistic for how evenly `hash` distributes
+/// `0..samples` across `buckets` buckets. A well distributed hash keeps
+/// this close to `buckets - 1`, the statistic's expected value under a
+/// uniform distribution.
+fn chi_squared<F: Fn(&[u8]) -> u32>(hash: F, samples: u32, buckets: u32) -> f64 {
+    let mut counts = vec![0u32; buckets as usize];
+    for i in 0..samples {
+        let h = hash(&i.to_le_bytes());
+        counts[(h % buckets) as usize] += 1;
+    }
+
+    let expected = f64::from(samples) / f64::from(buckets);
+    counts
+        .iter()
+        .map(|&count| {
+            let diff = f64::from(count) - expected;
+            diff * diff / expected
+        })
+        .sum()
+}
+
+#[test]
+fn murmurhash3_x86_32_verification() {
+    assert_eq!(verification_32(murmurhash3_x86_32), 0xb0f57ee3);
+}
+
+#[test]
+fn murmurhash3_x64_128_verification() {
+    assert_eq!(verification_x64_128(), 0x6384ba69);
+}
+
+#[test]
+fn murmurhash3_x86_128_verification() {
+    assert_eq!(verification_x86_128(), 0xb3ece62a);
+}
+
+#[test]
+fn fnv32_verification() {
+    assert_eq!(
+        verification_32(|_, bytes| fnv32(FnvVariant::Fnv0, bytes)),
+        0x28676b00
+    );
+    assert_eq!(
+        verification_32(|_, bytes| fnv32(FnvVariant::Fnv1, bytes)),
+        0xcf67b6c3
+    );
+    assert_eq!(
+        verification_32(|_, bytes| fnv32(FnvVariant::Fnv1a, bytes)),
+        0x2b377407
+    );
+}
+
+#[test]
+fn fnv64_verification() {
+    assert_eq!(
+        verification_64(|_, bytes| fnv64(FnvVariant::Fnv0, bytes)),
+        0xcb26417c
+    );
+    assert_eq!(
+        verification_64(|_, bytes| fnv64(FnvVariant::Fnv1, bytes)),
+        0xbe3455ad
+    );
+    assert_eq!(
+        verification_64(|_, bytes| fnv64(FnvVariant::Fnv1a, bytes)),
+        0x9382e1c5
+    );
+}
+
+#[test]
+fn murmurhash3_x86_32_avalanche() {
+    let bias = avalanche_bias(|bytes| murmurhash3_x86_32(0, bytes), b"smhasher!");
+    assert!(bias > 0.4 && bias < 0.6, "avalanche bias out of range: {}", bias);
+}
+
+#[test]
+fn fnv1a_32_avalanche() {
+    let bias = avalanche_bias(|bytes| fnv32(FnvVariant::Fnv1a, bytes), b"smhasher!");
+    assert!(bias > 0.3 && bias < 0.7, "avalanche bias out of range: {}", bias);
+}
+
+#[test]
+fn murmurhash3_x86_32_distribution() {
+    let buckets = 256;
+    let samples = 65536;
+    let stat = chi_squared(|bytes| murmurhash3_x86_32(0, bytes), samples, buckets);
+
+    // The statistic's expected value under a uniform distribution is
+    // `buckets - 1`; give it a generous multiple of that as slack so the
+    // test only fails on a real distribution bug, not sampling noise.
+    assert!(
+        stat < f64::from(buckets) * 2.0,
+        "chi-squared statistic too high: {}",
+        stat
+    );
+}